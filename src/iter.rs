@@ -1,33 +1,247 @@
+use std::mem::MaybeUninit;
 use crate::CircularArray;
 
-
-
 pub struct CircularArrayIter<'a, const N: usize, T: 'a> {
     circular_array: &'a CircularArray<N, T>,
-    index: usize,
+    front: usize,
+    back: usize,
 }
 
 impl <'a, const N: usize, T: 'a> CircularArrayIter<'a, N, T> {
     pub fn new(circular_array: &'a CircularArray<N, T>) -> Self {
+        let back = circular_array.len;
         CircularArrayIter {
             circular_array,
-            index: 0,
+            front: 0,
+            back,
         }
     }
 }
-impl<'a, const N: usize, T> Iterator for CircularArrayIter<'a, N, T> where T: Default + Copy {
+impl<'a, const N: usize, T> Iterator for CircularArrayIter<'a, N, T> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let r = if self.index < self.circular_array.seq {
-            Some(&self.circular_array[self.index])
+        if self.front >= self.back {
+            return None;
+        }
+        let item = &self.circular_array[self.front];
+        self.front += 1;
+        Some(item)
+    }
+}
+
+impl<'a, const N: usize, T> DoubleEndedIterator for CircularArrayIter<'a, N, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(&self.circular_array[self.back])
+    }
+}
+
+impl<'a, const N: usize, T> ExactSizeIterator for CircularArrayIter<'a, N, T> {
+    fn len(&self) -> usize {
+        self.back - self.front
+    }
+}
+
+/// Yields `&mut T` in logical (push) order.
+///
+/// Holds the two wrap-boundary slices from
+/// [`as_mut_slices`](CircularArray::as_mut_slices) and walks them front to
+/// back (or back to front), so the borrow checker sees two disjoint
+/// mutable slices rather than one aliased buffer.
+pub struct IterMutIter<'a, const N: usize, T: 'a> {
+    first: &'a mut [T],
+    second: &'a mut [T],
+}
+
+impl<'a, const N: usize, T: 'a> IterMutIter<'a, N, T> {
+    pub(crate) fn new(first: &'a mut [T], second: &'a mut [T]) -> Self {
+        IterMutIter { first, second }
+    }
+}
+
+impl<'a, const N: usize, T> Iterator for IterMutIter<'a, N, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some((head, rest)) = std::mem::take(&mut self.first).split_first_mut() {
+            self.first = rest;
+            Some(head)
+        } else if let Some((head, rest)) = std::mem::take(&mut self.second).split_first_mut() {
+            self.second = rest;
+            Some(head)
         } else {
             None
-        };
-        self.index += 1;
-        r
+        }
     }
 }
+
+impl<'a, const N: usize, T> DoubleEndedIterator for IterMutIter<'a, N, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if let Some((tail, rest)) = std::mem::take(&mut self.second).split_last_mut() {
+            self.second = rest;
+            Some(tail)
+        } else if let Some((tail, rest)) = std::mem::take(&mut self.first).split_last_mut() {
+            self.first = rest;
+            Some(tail)
+        } else {
+            None
+        }
+    }
+}
+
+/// Owning iterator produced by [`IntoIterator for CircularArray`](IntoIterator).
+///
+/// Yields elements by value in logical (push) order, dropping any
+/// not-yet-yielded elements if the iterator itself is dropped early.
+pub struct IntoIter<const N: usize, T> {
+    arr: [MaybeUninit<T>; N],
+    start: usize,
+    front: usize,
+    back: usize,
+}
+
+impl<const N: usize, T> IntoIter<N, T> {
+    fn storage_index(&self, logical_index: usize) -> usize {
+        (self.start + logical_index) % N
+    }
+}
+
+impl<const N: usize, T> Iterator for IntoIter<N, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let idx = self.storage_index(self.front);
+        self.front += 1;
+        Some(unsafe { self.arr[idx].as_ptr().read() })
+    }
+}
+
+impl<const N: usize, T> DoubleEndedIterator for IntoIter<N, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        let idx = self.storage_index(self.back);
+        Some(unsafe { self.arr[idx].as_ptr().read() })
+    }
+}
+
+impl<const N: usize, T> Drop for IntoIter<N, T> {
+    fn drop(&mut self) {
+        for logical_index in self.front..self.back {
+            let idx = self.storage_index(logical_index);
+            unsafe {
+                std::ptr::drop_in_place(self.arr[idx].as_mut_ptr());
+            }
+        }
+    }
+}
+
+impl<const N: usize, T> IntoIterator for CircularArray<N, T> {
+    type Item = T;
+    type IntoIter = IntoIter<N, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let this = std::mem::ManuallyDrop::new(self);
+        let arr = unsafe { std::ptr::read(&this.arr) };
+        IntoIter {
+            arr,
+            start: this.start,
+            front: 0,
+            back: this.len,
+        }
+    }
+}
+
+/// Iterator returned by [`CircularArray::drain`].
+///
+/// Yields the drained elements by value in logical order. On drop, shifts
+/// the elements after the drained range down to close the gap.
+pub struct Drain<'a, const N: usize, T> {
+    circular_array: &'a mut CircularArray<N, T>,
+    original_start: usize,
+    drain_end: usize,
+    tail_len: usize,
+    front: usize,
+}
+
+impl<'a, const N: usize, T> Drain<'a, N, T> {
+    pub(crate) fn new(
+        circular_array: &'a mut CircularArray<N, T>,
+        original_start: usize,
+        drain_start: usize,
+        drain_end: usize,
+        tail_len: usize,
+    ) -> Self {
+        Drain {
+            circular_array,
+            original_start,
+            drain_end,
+            tail_len,
+            front: drain_start,
+        }
+    }
+}
+
+impl<'a, const N: usize, T> Iterator for Drain<'a, N, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.drain_end {
+            return None;
+        }
+        let idx = (self.original_start + self.front) % N;
+        self.front += 1;
+        Some(unsafe { self.circular_array.arr[idx].as_ptr().read() })
+    }
+}
+
+impl<'a, const N: usize, T> Drop for Drain<'a, N, T> {
+    fn drop(&mut self) {
+        // A guard so that if dropping a remaining un-yielded element
+        // panics, the tail is still shifted down and `len` still restored
+        // on unwind, rather than leaking everything after the drained
+        // range forever.
+        struct DropGuard<'r, 'a, const N: usize, T>(&'r mut Drain<'a, N, T>);
+
+        impl<'r, 'a, const N: usize, T> Drop for DropGuard<'r, 'a, N, T> {
+            fn drop(&mut self) {
+                let drain = &mut *self.0;
+
+                // `circular_array.len` was truncated to `drain_start` up
+                // front by `CircularArray::drain`, and nothing else can
+                // have touched it while this `Drain` held the exclusive
+                // borrow.
+                let drain_start = drain.circular_array.len;
+                let drained_count = drain.drain_end - drain_start;
+                if drained_count > 0 {
+                    for i in 0..drain.tail_len {
+                        let src = (drain.original_start + drain.drain_end + i) % N;
+                        let dst = (drain.original_start + drain_start + i) % N;
+                        unsafe {
+                            let value = drain.circular_array.arr[src].as_ptr().read();
+                            drain.circular_array.arr[dst] = MaybeUninit::new(value);
+                        }
+                    }
+                }
+                drain.circular_array.len = drain_start + drain.tail_len;
+            }
+        }
+
+        let guard = DropGuard(self);
+        // Drop any remaining un-yielded drained elements.
+        for _ in guard.0.by_ref() {}
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -54,4 +268,196 @@ mod test {
         let r: u32 = arr.iter().sum();
         assert_eq!(r, 6);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_circular_array_iter_wrapped_and_rev() {
+        let mut arr = CircularArray::<3, u32>::new();
+        arr.push(1);
+        arr.push(2);
+        arr.push(3);
+        arr.push(4);
+        assert_eq!(arr.iter().collect::<Vec<_>>(), vec![&2, &3, &4]);
+        assert_eq!(arr.iter().rev().collect::<Vec<_>>(), vec![&4, &3, &2]);
+        assert_eq!(arr.iter().next_back(), Some(&4));
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut arr = CircularArray::<3, u32>::new();
+        arr.push(1);
+        arr.push(2);
+        arr.push(3);
+        arr.push(4);
+        for v in arr.iter_mut() {
+            *v *= 10;
+        }
+        assert_eq!(arr.iter().collect::<Vec<_>>(), vec![&20, &30, &40]);
+    }
+
+    #[test]
+    fn test_iter_mut_rev() {
+        let mut arr = CircularArray::<3, u32>::new();
+        arr.push(1);
+        arr.push(2);
+        arr.push(3);
+        assert_eq!(arr.iter_mut().rev().map(|v| *v).collect::<Vec<_>>(), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let mut arr = CircularArray::<3, String>::new();
+        arr.push("a".to_string());
+        arr.push("b".to_string());
+        arr.push("c".to_string());
+        arr.push("d".to_string());
+        let collected: Vec<String> = arr.into_iter().collect();
+        assert_eq!(collected, vec!["b", "c", "d"]);
+    }
+
+    #[test]
+    fn test_into_iter_rev() {
+        let mut arr = CircularArray::<3, u32>::new();
+        arr.push(1);
+        arr.push(2);
+        arr.push(3);
+        let collected: Vec<u32> = arr.into_iter().rev().collect();
+        assert_eq!(collected, vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn test_into_iter_partial_consumption_drops_rest() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let drops = Rc::new(RefCell::new(Vec::new()));
+
+        struct Tracked(Rc<RefCell<Vec<i32>>>, i32);
+        impl Drop for Tracked {
+            fn drop(&mut self) {
+                self.0.borrow_mut().push(self.1);
+            }
+        }
+
+        let mut arr = CircularArray::<3, Tracked>::new();
+        arr.push(Tracked(drops.clone(), 1));
+        arr.push(Tracked(drops.clone(), 2));
+        arr.push(Tracked(drops.clone(), 3));
+
+        let mut into_iter = arr.into_iter();
+        let first = into_iter.next().unwrap();
+        assert_eq!(first.1, 1);
+        drop(first);
+        drop(into_iter);
+
+        let mut dropped = drops.borrow().clone();
+        dropped.sort();
+        assert_eq!(dropped, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_drain_middle() {
+        let mut arr = CircularArray::<5, u32>::new();
+        arr.push(1);
+        arr.push(2);
+        arr.push(3);
+        arr.push(4);
+        arr.push(5);
+        let removed: Vec<u32> = arr.drain(1..3).collect();
+        assert_eq!(removed, vec![2, 3]);
+        assert_eq!(arr.len(), 3);
+        assert_eq!(arr.iter().collect::<Vec<_>>(), vec![&1, &4, &5]);
+    }
+
+    #[test]
+    fn test_drain_across_wrap_boundary() {
+        let mut arr = CircularArray::<3, u32>::new();
+        arr.push(1);
+        arr.push(2);
+        arr.push(3);
+        arr.push(4);
+        arr.push(5);
+        // logical order is now [3, 4, 5], stored wrapped around the array
+        let removed: Vec<u32> = arr.drain(0..2).collect();
+        assert_eq!(removed, vec![3, 4]);
+        assert_eq!(arr.iter().collect::<Vec<_>>(), vec![&5]);
+    }
+
+    #[test]
+    fn test_drain_empty_range_is_noop() {
+        let mut arr = CircularArray::<3, u32>::new();
+        arr.push(1);
+        arr.push(2);
+        arr.push(3);
+        let removed: Vec<u32> = arr.drain(1..1).collect();
+        assert!(removed.is_empty());
+        assert_eq!(arr.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+    }
+
+    #[test]
+    fn test_drain_full_range() {
+        let mut arr = CircularArray::<3, u32>::new();
+        arr.push(1);
+        arr.push(2);
+        arr.push(3);
+        let removed: Vec<u32> = arr.drain(..).collect();
+        assert_eq!(removed, vec![1, 2, 3]);
+        assert!(arr.is_empty());
+    }
+
+    #[test]
+    fn test_drain_dropped_without_full_consumption_still_closes_gap() {
+        let mut arr = CircularArray::<5, u32>::new();
+        arr.push(1);
+        arr.push(2);
+        arr.push(3);
+        arr.push(4);
+        arr.push(5);
+        {
+            let mut drain = arr.drain(1..4);
+            assert_eq!(drain.next(), Some(2));
+            // remaining items (3, 4) are dropped here without being yielded
+        }
+        assert_eq!(arr.iter().collect::<Vec<_>>(), vec![&1, &5]);
+    }
+
+    #[test]
+    fn test_drain_forgotten_does_not_double_drop() {
+        let mut arr = CircularArray::<5, u32>::new();
+        arr.push(1);
+        arr.push(2);
+        arr.push(3);
+        arr.push(4);
+        arr.push(5);
+        std::mem::forget(arr.drain(1..4));
+        // leaked the drained range and the tail after it, per `Vec::drain`
+        assert_eq!(arr.iter().collect::<Vec<_>>(), vec![&1]);
+    }
+
+    #[test]
+    fn test_drain_survives_panicking_drop() {
+        struct PanicsOnDrop(u32);
+        impl Drop for PanicsOnDrop {
+            fn drop(&mut self) {
+                if self.0 == 3 {
+                    panic!("boom");
+                }
+            }
+        }
+
+        let mut arr = CircularArray::<5, PanicsOnDrop>::new();
+        arr.push(PanicsOnDrop(1));
+        arr.push(PanicsOnDrop(2));
+        arr.push(PanicsOnDrop(3));
+        arr.push(PanicsOnDrop(4));
+        arr.push(PanicsOnDrop(5));
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            arr.drain(1..4);
+        }));
+        assert!(result.is_err());
+
+        // even though dropping element 3 panicked mid-drain, the tail
+        // (element 5) must not be silently leaked
+        assert_eq!(arr.iter().map(|v| v.0).collect::<Vec<_>>(), vec![1, 5]);
+    }
+}
@@ -1,79 +1,189 @@
 pub mod iter;
 
-use std::fmt::{Debug, Display};
-use std::ops::{Add, Index, IndexMut};
-use crate::iter::CircularArrayIter;
+use std::cmp::Ordering;
+use std::fmt::{self, Debug};
+use std::mem::MaybeUninit;
+use std::ops::{Bound, Index, IndexMut, RangeBounds};
+use crate::iter::{CircularArrayIter, Drain, IterMutIter};
 
 /// A circular array that allows infinite pushes into a fixed-size array.
-#[derive(Debug)]
 pub struct CircularArray<const N: usize, T> {
-    arr: [T;N],
-    start: usize,
-    len: usize,
+    pub(crate) arr: [MaybeUninit<T>; N],
+    pub(crate) start: usize,
+    pub(crate) len: usize,
 }
 
-impl<const N: usize, T> CircularArray<N, T> where T: Copy + Default + Debug + Display {
+impl<const N: usize, T> CircularArray<N, T> {
     pub fn new() -> Self {
         Self {
-            arr: [T::default(); N],
+            arr: std::array::from_fn(|_| MaybeUninit::uninit()),
             start: 0,
             len: 0,
         }
     }
 
+    /// Equivalent to [`push_back`](Self::push_back).
+    ///
     /// # example
     /// ```
     /// use circular_array::CircularArray;
-    /// #[test]
-    /// fn test_push() {
-    ///     let mut arr = CircularArray::<3, u32>::new();
-    ///     arr.push(1);
-    ///     arr.push(2);
-    ///     arr.push(3);
-    ///     assert_eq!(arr.to_array(), [1, 2, 3]);
-    ///     arr.push(4);
-    ///     assert_eq!(arr.to_array(), [2, 3, 4]);
-    /// }
+    /// let mut arr = CircularArray::<3, u32>::new();
+    /// arr.push(1);
+    /// arr.push(2);
+    /// arr.push(3);
+    /// assert_eq!(arr.to_array(), [1, 2, 3]);
+    /// arr.push(4);
+    /// assert_eq!(arr.to_array(), [2, 3, 4]);
     /// ```
-
     pub fn push(&mut self, item: T) {
-        if self.len >= N {
-            self.arr[self.start] = item;
+        self.push_back(item);
+    }
+
+    /// Pushes `item` onto the back (newest end). If the buffer is already
+    /// at capacity, the front (oldest) element is evicted to make room.
+    pub fn push_back(&mut self, item: T) {
+        if self.len == N {
+            unsafe {
+                std::ptr::drop_in_place(self.arr[self.start].as_mut_ptr());
+            }
+            self.arr[self.start] = MaybeUninit::new(item);
+            self.start = (self.start + 1) % N;
+        } else {
+            let idx = (self.start + self.len) % N;
+            self.arr[idx] = MaybeUninit::new(item);
+            self.len += 1;
+        }
+    }
+
+    /// Pushes `item` onto the front (oldest end). If the buffer is already
+    /// at capacity, the back (newest) element is evicted to make room.
+    pub fn push_front(&mut self, item: T) {
+        if self.len == N {
+            let back_idx = (self.start + self.len - 1) % N;
+            unsafe {
+                std::ptr::drop_in_place(self.arr[back_idx].as_mut_ptr());
+            }
         } else {
-            self.arr[self.len] = item;
+            self.len += 1;
+        }
+        self.start = (self.start + N - 1) % N;
+        self.arr[self.start] = MaybeUninit::new(item);
+    }
+
+    /// Removes and returns the front (oldest) element, or `None` if empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
         }
+        let idx = self.start;
+        let item = unsafe { self.arr[idx].as_ptr().read() };
         self.start = (self.start + 1) % N;
-        self.len += 1;
+        self.len -= 1;
+        Some(item)
     }
 
-    /// ## Examples
+    /// Removes and returns the back (newest) element, or `None` if empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        let idx = (self.start + self.len - 1) % N;
+        self.len -= 1;
+        Some(unsafe { self.arr[idx].as_ptr().read() })
+    }
+
+    /// Removes the elements in the given logical index range and returns
+    /// an iterator yielding them in order. Elements after the range shift
+    /// down to close the gap, so the remaining elements stay contiguous
+    /// in logical order afterward.
+    ///
+    /// If the returned [`Drain`] is leaked (e.g. via `mem::forget`)
+    /// instead of dropped normally, the buffer is truncated up front to
+    /// just before the drained range, so a leak can never cause a double
+    /// drop — but it does mean both the drained range *and* everything
+    /// after it are leaked along with it. This mirrors `Vec::drain`.
+    ///
+    /// # example
     /// ```
-    ///     use circular_array::CircularArray;
-    /// #[test]
-    ///     fn test_to_array() {
-    ///         let mut arr = CircularArray::<3, u32>::new();
-    ///         arr.push(1);
-    ///         arr.push(2);
-    ///         arr.push(3);
-    ///         assert_eq!(arr.to_array(), [1, 2, 3]);
-    ///         arr.push(4);
-    ///         assert_eq!(arr.to_array(), [2, 3, 4]);
-    ///     }
+    /// use circular_array::CircularArray;
+    /// let mut arr = CircularArray::<5, u32>::new();
+    /// arr.push(1);
+    /// arr.push(2);
+    /// arr.push(3);
+    /// arr.push(4);
+    /// arr.push(5);
+    /// let removed: Vec<u32> = arr.drain(1..3).collect();
+    /// assert_eq!(removed, vec![2, 3]);
+    /// assert_eq!(arr.iter().collect::<Vec<_>>(), vec![&1, &4, &5]);
     /// ```
-    pub fn to_array(&self) -> [T;N] {
-        unsafe {
-            let mut arr = [T::default(); N];
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, N, T> {
+        let len = self.len;
+        let drain_start = match range.start_bound() {
+            Bound::Included(&n) => n,
+            Bound::Excluded(&n) => n + 1,
+            Bound::Unbounded => 0,
+        };
+        let drain_end = match range.end_bound() {
+            Bound::Included(&n) => n + 1,
+            Bound::Excluded(&n) => n,
+            Bound::Unbounded => len,
+        };
+        assert!(drain_start <= drain_end, "drain start must not exceed end");
+        assert!(drain_end <= len, "drain range out of bounds");
 
-            let src_ptr = self.arr.as_ptr();
-            let dest_ptr = arr.as_mut_ptr();
+        let original_start = self.start;
+        let tail_len = len - drain_end;
+        // Truncate immediately so a leaked `Drain` can't leave the buffer
+        // pointing past live elements.
+        self.len = drain_start;
+        Drain::new(self, original_start, drain_start, drain_end, tail_len)
+    }
 
-            if self.len >= N && self.start > 0 {
-                std::ptr::copy_nonoverlapping(src_ptr.add(self.start), dest_ptr, N - self.start);
-                std::ptr::copy_nonoverlapping(src_ptr, dest_ptr.add(N - self.start), N - self.start);
-            } else {
-                std::ptr::copy_nonoverlapping(src_ptr, dest_ptr, N);
+    /// Returns the contents as two slices in logical (push) order, without
+    /// copying. The first slice holds the older elements, the second holds
+    /// the newer ones; concatenating them yields the same order as
+    /// [`to_array`](Self::to_array).
+    ///
+    /// # example
+    /// ```
+    /// use circular_array::CircularArray;
+    /// let mut arr = CircularArray::<3, u32>::new();
+    /// arr.push(1);
+    /// arr.push(2);
+    /// arr.push(3);
+    /// arr.push(4);
+    /// let (first, second) = arr.as_slices();
+    /// assert_eq!(first, &[2, 3]);
+    /// assert_eq!(second, &[4]);
+    /// ```
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        if self.start + self.len <= N {
+            (unsafe { slice_assume_init_ref(&self.arr[self.start..self.start + self.len]) }, &[])
+        } else {
+            let (before_start, from_start) = self.arr.split_at(self.start);
+            let wrapped_len = self.len - from_start.len();
+            unsafe {
+                (
+                    slice_assume_init_ref(from_start),
+                    slice_assume_init_ref(&before_start[..wrapped_len]),
+                )
+            }
+        }
+    }
+
+    /// Mutable counterpart of [`as_slices`](Self::as_slices).
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        if self.start + self.len <= N {
+            (unsafe { slice_assume_init_mut(&mut self.arr[self.start..self.start + self.len]) }, &mut [])
+        } else {
+            let (before_start, from_start) = self.arr.split_at_mut(self.start);
+            let wrapped_len = self.len - from_start.len();
+            unsafe {
+                (
+                    slice_assume_init_mut(from_start),
+                    slice_assume_init_mut(&mut before_start[..wrapped_len]),
+                )
             }
-            arr
         }
     }
 
@@ -90,67 +200,195 @@ impl<const N: usize, T> CircularArray<N, T> where T: Copy + Default + Debug + Di
     /// assert_eq!(iter.next(), Some(&3));
     /// assert_eq!(iter.next(), None);
     /// ```
-    pub fn iter(&self) -> CircularArrayIter<N, T> {
-        CircularArrayIter::new(&self)
+    pub fn iter(&self) -> CircularArrayIter<'_, N, T> {
+        CircularArrayIter::new(self)
     }
 
+    /// Returns an iterator yielding `&mut T` in logical (push) order.
+    pub fn iter_mut(&mut self) -> IterMutIter<'_, N, T> {
+        let (first, second) = self.as_mut_slices();
+        IterMutIter::new(first, second)
+    }
 
     /// # Example
     /// ```
     /// use circular_array::CircularArray;
-    /// #[test]
-    /// fn test_last() {
-    ///     let mut arr = CircularArray::<3, u32>::new();
-    ///     assert_eq!(arr.last(), None);
-    ///     arr.push(1);
-    ///     assert_eq!(arr.last(), Some(1).as_ref());
-    ///     arr.push(2);
-    ///     arr.push(3);
-    ///     arr.push(4);
-    ///     assert_eq!(arr.last(), Some(4).as_ref());
-    /// }
+    /// let mut arr = CircularArray::<3, u32>::new();
+    /// assert_eq!(arr.last(), None);
+    /// arr.push(1);
+    /// assert_eq!(arr.last(), Some(1).as_ref());
+    /// arr.push(2);
+    /// arr.push(3);
+    /// arr.push(4);
+    /// assert_eq!(arr.last(), Some(4).as_ref());
     /// ```
     pub fn last(&self) -> Option<&T> {
-        if self.len >= N  {
-            Some(&self[N-1])
-        } else if self.len > 0 {
-            Some(&self[self.len -1])
-        } else {
+        if self.len == 0 {
             None
+        } else {
+            Some(&self[self.len - 1])
         }
     }
 
+    /// The number of live elements currently stored, at most `N`.
     pub fn len(&self) -> usize {
         self.len
     }
+
+    /// Returns `true` if the buffer holds no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the logical index of the first element matching `pred`,
+    /// scanning from the oldest element forward.
+    ///
+    /// # example
+    /// ```
+    /// use circular_array::CircularArray;
+    /// let mut arr = CircularArray::<3, u32>::new();
+    /// arr.push(1);
+    /// arr.push(2);
+    /// arr.push(3);
+    /// arr.push(4);
+    /// assert_eq!(arr.position(|&v| v == 2), Some(0));
+    /// ```
+    pub fn position<P: FnMut(&T) -> bool>(&self, pred: P) -> Option<usize> {
+        self.iter().position(pred)
+    }
+
+    /// Returns the logical index of the last element matching `pred`,
+    /// scanning from the newest element backward.
+    pub fn rposition<P: FnMut(&T) -> bool>(&self, pred: P) -> Option<usize> {
+        self.iter().rposition(pred)
+    }
+
+    /// Returns a reference to the first element matching `pred`, in
+    /// logical order.
+    pub fn find<P: FnMut(&T) -> bool>(&self, mut pred: P) -> Option<&T> {
+        self.iter().find(|v| pred(v))
+    }
+
+    /// Binary searches the logical sequence with a comparator function,
+    /// assuming it is sorted in that order. See [`binary_search`](Self::binary_search).
+    ///
+    /// # example
+    /// ```
+    /// use circular_array::CircularArray;
+    /// let mut arr = CircularArray::<3, u32>::new();
+    /// arr.push(10);
+    /// arr.push(20);
+    /// arr.push(30);
+    /// arr.push(40);
+    /// // wrapped to logical order [20, 30, 40]
+    /// assert_eq!(arr.binary_search_by(|v| v.cmp(&30)), Ok(1));
+    /// assert_eq!(arr.binary_search_by(|v| v.cmp(&25)), Err(1));
+    /// ```
+    pub fn binary_search_by<F: FnMut(&T) -> Ordering>(&self, mut f: F) -> Result<usize, usize> {
+        let mut lo = 0;
+        let mut hi = self.len;
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            match f(&self[mid]) {
+                Ordering::Equal => return Ok(mid),
+                Ordering::Less => lo = mid + 1,
+                Ordering::Greater => hi = mid,
+            }
+        }
+        Err(lo)
+    }
+}
+
+impl<const N: usize, T> Default for CircularArray<N, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize, T: Ord> CircularArray<N, T> {
+    /// Binary searches the logical sequence for `x`, assuming it is sorted
+    /// in that order (e.g. a sliding window of timestamps). Returns
+    /// `Ok(index)` of a matching element, or `Err(index)` of where it
+    /// would be inserted to keep the sequence sorted.
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize> {
+        self.binary_search_by(|v| v.cmp(x))
+    }
+}
+
+impl<const N: usize, T: PartialEq> CircularArray<N, T> {
+    /// Returns `true` if the buffer contains an element equal to `x`.
+    pub fn contains(&self, x: &T) -> bool {
+        self.iter().any(|v| v == x)
+    }
+}
+
+impl<const N: usize, T: Copy + Default> CircularArray<N, T> {
+    /// ## Examples
+    /// ```
+    /// use circular_array::CircularArray;
+    /// let mut arr = CircularArray::<3, u32>::new();
+    /// arr.push(1);
+    /// arr.push(2);
+    /// arr.push(3);
+    /// assert_eq!(arr.to_array(), [1, 2, 3]);
+    /// arr.push(4);
+    /// assert_eq!(arr.to_array(), [2, 3, 4]);
+    /// ```
+    pub fn to_array(&self) -> [T;N] {
+        let mut arr = [T::default(); N];
+        let (first, second) = self.as_slices();
+        arr[..first.len()].copy_from_slice(first);
+        arr[first.len()..first.len() + second.len()].copy_from_slice(second);
+        arr
+    }
+}
+
+/// # Safety
+/// Every element in `slice` must be initialized.
+unsafe fn slice_assume_init_ref<T>(slice: &[MaybeUninit<T>]) -> &[T] {
+    unsafe { &*(slice as *const [MaybeUninit<T>] as *const [T]) }
+}
+
+/// # Safety
+/// Every element in `slice` must be initialized.
+unsafe fn slice_assume_init_mut<T>(slice: &mut [MaybeUninit<T>]) -> &mut [T] {
+    unsafe { &mut *(slice as *mut [MaybeUninit<T>] as *mut [T]) }
+}
+
+impl<const N: usize, T: Debug> Debug for CircularArray<N, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
 }
 
+impl<const N: usize, T> Drop for CircularArray<N, T> {
+    fn drop(&mut self) {
+        for i in 0..self.len {
+            let idx = (self.start + i) % N;
+            unsafe {
+                std::ptr::drop_in_place(self.arr[idx].as_mut_ptr());
+            }
+        }
+    }
+}
 
-impl<T, const N: usize> Index<usize> for CircularArray<N, T> where [T]: Index<usize>, T: Default + Copy
+impl<T, const N: usize> Index<usize> for CircularArray<N, T>
 {
-    type Output = <[T] as Index<usize>>::Output;
+    type Output = T;
 
     #[inline]
     fn index(&self, index: usize) -> &Self::Output {
-        if self.len >= N {
-            &self.arr[(self.start + index) % N]
-        } else {
-            &self.arr[index]
-        }
+        let idx = (self.start + index) % N;
+        unsafe { self.arr[idx].assume_init_ref() }
     }
 }
 
 impl<T, const N: usize> IndexMut<usize> for CircularArray<N, T>
-    where [T]: Index<usize>,
-          T: Default + Copy, usize: Add<usize> {
-
+{
     #[inline]
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        if self.len >= N {
-            &mut self.arr[(self.start + index) % N]
-        } else {
-            &mut self.arr[index]
-        }
+        let idx = (self.start + index) % N;
+        unsafe { self.arr[idx].assume_init_mut() }
     }
 }
 
@@ -164,9 +402,13 @@ mod tests {
         arr.push(1);
         arr.push(2);
         arr.push(3);
-        assert_eq!(arr.arr, [1, 2, 3]);
+        assert_eq!(arr.to_array(), [1, 2, 3]);
         arr.push(4);
-        assert_eq!(arr.arr, [4, 2, 3]);
+        unsafe {
+            assert_eq!(arr.arr[0].assume_init_ref(), &4);
+            assert_eq!(arr.arr[1].assume_init_ref(), &2);
+            assert_eq!(arr.arr[2].assume_init_ref(), &3);
+        }
     }
 
     #[test]
@@ -213,12 +455,133 @@ mod tests {
     fn test_len() {
         let mut arr = CircularArray::<3, u32>::new();
         assert_eq!(arr.len(), 0);
+        assert!(arr.is_empty());
         arr.push(1);
         assert_eq!(arr.len(), 1);
+        assert!(!arr.is_empty());
         arr.push(2);
         arr.push(3);
         arr.push(4);
-        assert_eq!(arr.len(), 4);
+        // len is clamped at capacity, not an unbounded push count
+        assert_eq!(arr.len(), 3);
     }
-}
 
+    #[test]
+    fn test_drop_runs_for_live_elements_only() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let drops = Rc::new(RefCell::new(Vec::new()));
+
+        struct Tracked(Rc<RefCell<Vec<i32>>>, i32);
+        impl Drop for Tracked {
+            fn drop(&mut self) {
+                self.0.borrow_mut().push(self.1);
+            }
+        }
+
+        {
+            let mut arr = CircularArray::<3, Tracked>::new();
+            arr.push(Tracked(drops.clone(), 1));
+            arr.push(Tracked(drops.clone(), 2));
+            arr.push(Tracked(drops.clone(), 3));
+            arr.push(Tracked(drops.clone(), 4));
+        }
+
+        let mut dropped = drops.borrow().clone();
+        dropped.sort();
+        assert_eq!(dropped, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_non_copy_non_default_type() {
+        let mut arr = CircularArray::<2, String>::new();
+        arr.push(String::from("a"));
+        arr.push(String::from("b"));
+        arr.push(String::from("c"));
+        assert_eq!(arr.as_slices(), (&["b".to_string()][..], &["c".to_string()][..]));
+    }
+
+    #[test]
+    fn test_position_rposition_find_contains() {
+        let mut arr = CircularArray::<3, u32>::new();
+        arr.push(1);
+        arr.push(2);
+        arr.push(3);
+        arr.push(4);
+        // wrapped to logical order [2, 3, 4]
+        assert_eq!(arr.position(|&v| v == 2), Some(0));
+        assert_eq!(arr.position(|&v| v == 5), None);
+        assert_eq!(arr.rposition(|&v| v % 2 == 0), Some(2));
+        assert_eq!(arr.find(|&v| v > 2), Some(&3));
+        assert!(arr.contains(&4));
+        assert!(!arr.contains(&1));
+    }
+
+    #[test]
+    fn test_binary_search() {
+        let mut arr = CircularArray::<3, u32>::new();
+        arr.push(10);
+        arr.push(20);
+        arr.push(30);
+        arr.push(40);
+        // wrapped to logical order [20, 30, 40]
+        assert_eq!(arr.binary_search(&30), Ok(1));
+        assert_eq!(arr.binary_search(&20), Ok(0));
+        assert_eq!(arr.binary_search(&25), Err(1));
+        assert_eq!(arr.binary_search(&5), Err(0));
+        assert_eq!(arr.binary_search(&100), Err(3));
+    }
+
+    #[test]
+    fn test_binary_search_empty() {
+        let arr = CircularArray::<3, u32>::new();
+        assert_eq!(arr.binary_search(&1), Err(0));
+    }
+
+    #[test]
+    fn test_push_front() {
+        let mut arr = CircularArray::<3, u32>::new();
+        arr.push_front(1);
+        arr.push_front(2);
+        arr.push_front(3);
+        assert_eq!(arr.to_array(), [3, 2, 1]);
+        // at capacity: push_front evicts the back (newest) element
+        arr.push_front(4);
+        assert_eq!(arr.to_array(), [4, 3, 2]);
+    }
+
+    #[test]
+    fn test_pop_front_and_pop_back() {
+        let mut arr = CircularArray::<3, u32>::new();
+        assert_eq!(arr.pop_front(), None);
+        assert_eq!(arr.pop_back(), None);
+
+        arr.push_back(1);
+        arr.push_back(2);
+        arr.push_back(3);
+        assert_eq!(arr.pop_back(), Some(3));
+        assert_eq!(arr.len(), 2);
+        assert_eq!(arr.pop_front(), Some(1));
+        assert_eq!(arr.len(), 1);
+        assert_eq!(arr.as_slices(), (&[2u32][..], &[][..]));
+        assert_eq!(arr.pop_back(), Some(2));
+        assert!(arr.is_empty());
+        assert_eq!(arr.pop_front(), None);
+    }
+
+    #[test]
+    fn test_mixed_push_pop_keeps_index_correct() {
+        let mut arr = CircularArray::<3, u32>::new();
+        arr.push_back(1);
+        arr.push_back(2);
+        arr.push_back(3);
+        assert_eq!(arr.pop_front(), Some(1));
+        arr.push_back(4);
+        arr.push_back(5);
+        // buffer is full and its contents wrap past the end of the storage
+        assert_eq!(arr.to_array(), [3, 4, 5]);
+        assert_eq!(arr[0], 3);
+        assert_eq!(arr.last(), Some(&5));
+    }
+}